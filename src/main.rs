@@ -1,9 +1,10 @@
 use std::io::{self, Write, Read};   // Этот блок обеспечивает импорт необходимых типов и макросов из библиотеки serde для работы с сериализацией и десериализацией данных.
 use termcolor::{Color, ColorChoice, ColorSpec, StandardStream, WriteColor};
-use chrono::{NaiveDateTime, Local};
+use chrono::{Datelike, Duration as ChronoDuration, NaiveDate, NaiveDateTime, NaiveTime, Local, Weekday};
 use std::fs::{File, OpenOptions};
+use std::collections::HashSet;
 use serde::{Serialize, Deserialize};
-use serde_json; 
+use serde_json;
 use serde_repr::{Serialize_repr, Deserialize_repr};
 
 const TASKS_FILE: &str = "tasks.json";   //Здесь объявляется константа TASKS_FILE, содержащая имя файла, в котором будут храниться задачи.
@@ -15,15 +16,40 @@ enum Priority {  // Это определение перечисления Prior
     Medium,
     Low,
 }
-#[derive(Clone, Serialize, Deserialize)] 
+#[derive(Clone, Serialize, Deserialize)]
 struct Task {   // Это определение структуры Task, представляющей собой задачу. Она содержит описание, флаг завершенности, приоритет и опциональное время выполнения.
+    id: u64,
     description: String,
     completed: bool,
     priority: Priority,
     due_time: Option<NaiveDateTime>,
+    tags: HashSet<String>,
+    time_entries: Vec<TimeEntry>,
+    dependencies: HashSet<u64>,
+    completed_at: Option<NaiveDateTime>,
 }
 
-impl Priority {      
+// Продолжительность в часах и минутах; минуты всегда меньше 60 — переполнение переносится в часы при создании.
+#[derive(Clone, Copy, Serialize, Deserialize)]
+struct Duration {
+    hours: u16,
+    minutes: u16,
+}
+
+impl Duration {
+    fn new(hours: u16, minutes: u16) -> Self {
+        Duration { hours: hours + minutes / 60, minutes: minutes % 60 }
+    }
+}
+
+// Одна запись об отработанном времени: когда и сколько.
+#[derive(Clone, Serialize, Deserialize)]
+struct TimeEntry {
+    logged_date: NaiveDate,
+    duration: Duration,
+}
+
+impl Priority {
     fn color(&self) -> ColorSpec {   // Этот блок определяет метод color() для типа Priority, который возвращает спецификацию цвета на основе приоритета.
         let mut color_spec = ColorSpec::new();
         match self {
@@ -33,53 +59,259 @@ impl Priority {
         };
         color_spec
     }
+
+    fn label(&self) -> &'static str {
+        match self {
+            Priority::High => "High",
+            Priority::Medium => "Medium",
+            Priority::Low => "Low",
+        }
+    }
 }
+// Сколько снимков состояния задач хранить для отмены операций.
+const MAX_UNDO_HISTORY: usize = 20;
+
 struct TaskManager {    // Это определение структуры TaskManager, представляющей менеджер задач. Он содержит вектор задач.
     tasks: Vec<Task>,
+    next_id: u64,
+    history: Vec<Vec<Task>>,
 }
 
-impl TaskManager {   // В этом блоке определяются методы для структуры TaskManager, такие как new(), add_task(), complete_task(), print_tasks(), load_tasks() и save_tasks(). Эти методы выполняют операции по добавлению, завершению, выводу, загрузке и сохранению задач.
+impl TaskManager {   // В этом блоке определяются методы для структуры TaskManager, такие как new(), add_task(), complete_task_by_id(), print_tasks(), load_tasks() и save_tasks(). Эти методы выполняют операции по добавлению, завершению, выводу, загрузке и сохранению задач.
     fn new() -> Self {
-        TaskManager { tasks: Vec::new() }
+        TaskManager { tasks: Vec::new(), next_id: 0, history: Vec::new() }
     }
 
-    fn add_task(&mut self, description: String, priority: Priority, due_time: Option<NaiveDateTime>) {
-        self.tasks.push(Task { description, completed: false, priority, due_time });
+    // Сохраняет снимок текущих задач перед мутацией, чтобы его можно было восстановить через undo.
+    fn push_history(&mut self) {
+        if self.history.len() >= MAX_UNDO_HISTORY {
+            self.history.remove(0);
+        }
+        self.history.push(self.tasks.clone());
+    }
+
+    // Восстанавливает до `steps` последних снимков; возвращает, сколько шагов реально отменено.
+    fn undo(&mut self, steps: usize) -> usize {
+        let mut undone = 0;
+        for _ in 0..steps {
+            match self.history.pop() {
+                Some(snapshot) => {
+                    self.tasks = snapshot;
+                    undone += 1;
+                }
+                None => break,
+            }
+        }
+        undone
+    }
+
+    fn add_task(&mut self, description: String, priority: Priority, due_time: Option<NaiveDateTime>, tags: HashSet<String>) {
+        self.push_history();
+        let id = self.next_id;
+        self.next_id += 1;
+        self.tasks.push(Task {
+            id,
+            description,
+            completed: false,
+            priority,
+            due_time,
+            tags,
+            time_entries: Vec::new(),
+            dependencies: HashSet::new(),
+            completed_at: None,
+        });
         self.tasks.sort_by_key(|task| task.priority);
     }
 
-    fn complete_task(&mut self, index: usize) {
-        self.tasks.get_mut(index).map(|task| task.completed = true);
+    // Отмечает задачу с указанным ID завершённой. ID стабилен, в отличие от позиции в tasks,
+    // которая меняется при пересортировке по приоритету в add_task.
+    fn complete_task_by_id(&mut self, id: u64) -> bool {
+        if self.find_task_by_id(id).is_none() {
+            return false;
+        }
+        self.push_history();
+        let task = self.tasks.iter_mut().find(|task| task.id == id).unwrap();
+        task.set_completed(true);
+        true
+    }
+
+    // Удаляет задачу с указанным ID.
+    fn delete_task_by_id(&mut self, id: u64) -> bool {
+        if self.find_task_by_id(id).is_none() {
+            return false;
+        }
+        self.push_history();
+        self.tasks.retain(|task| task.id != id);
+        true
+    }
+
+    fn find_task_by_id(&self, id: u64) -> Option<&Task> {
+        self.tasks.iter().find(|task| task.id == id)
+    }
+
+    // Обходит граф зависимостей в глубину, начиная с start, и проверяет, достижим ли target.
+    fn is_reachable(&self, start: u64, target: u64, visited: &mut HashSet<u64>) -> bool {
+        if start == target {
+            return true;
+        }
+        if !visited.insert(start) {
+            return false;
+        }
+        match self.find_task_by_id(start) {
+            Some(task) => task.dependencies.iter().any(|&dep| self.is_reachable(dep, target, visited)),
+            None => false,
+        }
+    }
+
+    // Добавляет зависимость task_id -> depends_on_id, отклоняя её, если она образует цикл.
+    fn add_dependency(&mut self, task_id: u64, depends_on_id: u64) -> Result<(), String> {
+        if self.find_task_by_id(task_id).is_none() || self.find_task_by_id(depends_on_id).is_none() {
+            return Err("Задача с таким ID не найдена.".to_string());
+        }
+
+        let mut visited = HashSet::new();
+        if self.is_reachable(depends_on_id, task_id, &mut visited) {
+            return Err("Эта зависимость создаст цикл.".to_string());
+        }
+
+        let task = self.tasks.iter_mut().find(|task| task.id == task_id).unwrap();
+        task.dependencies.insert(depends_on_id);
+        Ok(())
+    }
+
+    // Задача заблокирована, если хотя бы одна из её зависимостей ещё не завершена.
+    fn is_blocked(&self, task: &Task) -> bool {
+        task.dependencies.iter().any(|dep_id| {
+            self.find_task_by_id(*dep_id).map_or(false, |dep| !dep.completed)
+        })
+    }
+
+    // Добавляет запись о потраченном времени к задаче с указанным ID.
+    fn track_time(&mut self, id: u64, duration: Duration) -> bool {
+        match self.tasks.iter_mut().find(|task| task.id == id) {
+            Some(task) => {
+                task.time_entries.push(TimeEntry { logged_date: Local::now().date_naive(), duration });
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Возвращает задачи, у которых среди тегов есть указанный tag.
+    fn tasks_with_tag(&self, tag: &str) -> Vec<&Task> {
+        self.tasks.iter().filter(|task| task.tags.contains(tag)).collect()
     }
 
     fn print_tasks(&self) {
         let stdout = StandardStream::stdout(ColorChoice::Always);
         let mut stdout = stdout.lock();
 
-        for (index, task) in self.tasks.iter().enumerate() {
-            stdout.set_color(&task.priority.color()).unwrap();
+        for task in self.tasks.iter() {
+            let blocked = self.is_blocked(task);
+            Self::print_task_line(&mut stdout, task, blocked);
+        }
+    }
+
+    // Выводит задачи, у которых есть указанный тег.
+    fn print_tasks_with_tag(&self, tag: &str) {
+        let stdout = StandardStream::stdout(ColorChoice::Always);
+        let mut stdout = stdout.lock();
+
+        for task in self.tasks_with_tag(tag) {
+            let blocked = self.is_blocked(task);
+            Self::print_task_line(&mut stdout, task, blocked);
+        }
+    }
+
+    // Табличный вид: колонки status/ID/priority/description/due/time-left/completed,
+    // выровненные по ширине, вместо свободного write! у print_task_line.
+    fn print_tasks_table(&self) {
+        let stdout = StandardStream::stdout(ColorChoice::Always);
+        let mut stdout = stdout.lock();
+
+        writeln!(
+            stdout, "{:<3} {:<5} {:<8} {:<30} {:<20} {:<15} {:<19}",
+            "", "ID", "Priority", "Description", "Due", "Time left", "Completed",
+        ).unwrap();
+
+        for task in self.tasks.iter() {
             let status = if task.completed { "[x]" } else { "[ ]" };
-            write!(stdout, "{} {}: {}", status, index, task.description).unwrap();
+            let due = task.due_time
+                .map(|due_time| due_time.format("%H:%M %d-%m-%Y").to_string())
+                .unwrap_or_else(|| "-".to_string());
+            let time_left = match task.due_time {
+                Some(due_time) => {
+                    let now = Local::now().naive_local();
+                    if due_time > now {
+                        format!("Осталось {}ч", (due_time - now).num_hours())
+                    } else {
+                        "Просрочено".to_string()
+                    }
+                }
+                None => "-".to_string(),
+            };
+            let completed_at = task.completed_at
+                .map(|completed_at| completed_at.format("%H:%M %d-%m-%Y").to_string())
+                .unwrap_or_else(|| "-".to_string());
 
-            if let Some(due_time) = task.due_time {
-                let now = Local::now().naive_local();
-                let (prefix, color) = if due_time > now {
-                    let time_left = due_time - now;
-                    (format!("Осталось {} часов", time_left.num_hours()), Color::Green)
-                } else {
-                    ("Просрочено".to_string(), Color::Red)
-                };
-                stdout.set_color(ColorSpec::new().set_fg(Some(color))).unwrap();
-                writeln!(stdout, " ({})", prefix).unwrap();
-                stdout.reset().unwrap();
+            stdout.set_color(&task.priority.color()).unwrap();
+            writeln!(
+                stdout, "{:<3} {:<5} {:<8} {:<30} {:<20} {:<15} {:<19}",
+                status, task.id, task.priority.label(), task.description, due, time_left, completed_at,
+            ).unwrap();
+            stdout.reset().unwrap();
+        }
+    }
+
+    // Выводит одну строку задачи в цвете её приоритета; используется и для полного, и для отфильтрованного списка.
+    // ID стабилен и остаётся источником истины для complete/delete, в отличие от позиции в tasks.
+    fn print_task_line(stdout: &mut impl WriteColor, task: &Task, blocked: bool) {
+        stdout.set_color(&task.priority.color()).unwrap();
+        let status = if task.completed { "[x]" } else { "[ ]" };
+        write!(stdout, "{} {}: {}", status, task.id, task.description).unwrap();
+
+        if blocked {
+            write!(stdout, " [заблокировано]").unwrap();
+        }
+
+        if !task.time_entries.is_empty() {
+            let total_time = task.total_time();
+            write!(stdout, " [{}ч {}м]", total_time.hours, total_time.minutes).unwrap();
+        }
+
+        if let Some(due_time) = task.due_time {
+            let now = Local::now().naive_local();
+            let (prefix, color) = if due_time > now {
+                let time_left = due_time - now;
+                (format!("Осталось {} часов", time_left.num_hours()), Color::Green)
             } else {
-                writeln!(stdout).unwrap();
-            }
+                ("Просрочено".to_string(), Color::Red)
+            };
+            stdout.set_color(ColorSpec::new().set_fg(Some(color))).unwrap();
+            writeln!(stdout, " ({})", prefix).unwrap();
+            stdout.reset().unwrap();
+        } else {
+            writeln!(stdout).unwrap();
         }
     }
 }
 
 impl Task {  // Здесь определены методы для структуры Task, такие как to_json() и from_json(), которые обеспечивают сериализацию и десериализацию задачи в формат JSON.
+    // Устанавливает флаг completed и связанный с ним completed_at; completed_at всегда
+    // очищается, если задачу когда-либо сделают незавершённой.
+    fn set_completed(&mut self, completed: bool) {
+        self.completed = completed;
+        self.completed_at = if completed { Some(Local::now().naive_local()) } else { None };
+    }
+
+    // Суммирует все записи time_entries в одну продолжительность.
+    fn total_time(&self) -> Duration {
+        let total_minutes: u32 = self.time_entries.iter()
+            .map(|entry| entry.duration.hours as u32 * 60 + entry.duration.minutes as u32)
+            .sum();
+        Duration::new((total_minutes / 60) as u16, (total_minutes % 60) as u16)
+    }
+
     fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string(self)
     }
@@ -96,6 +328,7 @@ impl TaskManager {
         file.read_to_string(&mut contents)?;
 
         let parsed_tasks: Vec<Task> = serde_json::from_str(&contents)?;
+        self.next_id = parsed_tasks.iter().map(|task| task.id + 1).max().unwrap_or(0).max(self.next_id);
         self.tasks = parsed_tasks;
 
         Ok(())
@@ -128,7 +361,7 @@ fn main() {  // Это функция main(), которая является т
     }
 
     loop {
-        print!("Введите команду (add/complete/print/quit): "); // Приглашение пользователю ввести команду.
+        print!("Введите команду (add/complete/delete/print/list/filter/track/depend/undo/quit): "); // Приглашение пользователю ввести команду.
         io::stdout().flush().unwrap();  // Очистка буфера вывода.
 
         let mut input = String::new(); // Создание строки для хранения ввода пользователя.
@@ -136,6 +369,11 @@ fn main() {  // Это функция main(), которая является т
         let command = input.trim(); // Удаление лишних пробелов из введенной строки.
 
         match command {
+            cmd if cmd == "undo" || cmd.starts_with("undo ") => {  // Если пользователь ввел "undo" (опционально с числом шагов), откатываем последние изменения.
+                let steps: usize = cmd.strip_prefix("undo").unwrap().trim().parse().unwrap_or(1);
+                let undone = task_manager.undo(steps);
+                println!("Отменено шагов: {}.", undone);
+            }
             "add" => {  // Если пользователь ввел "add", добавляем новую задачу.
                 print!("Введите описание задачи: ");
                 io::stdout().flush().unwrap();
@@ -153,38 +391,117 @@ fn main() {  // Это функция main(), которая является т
                     }
                 };
 
-                print!("Введите срок выполнения (ЧЧ:ММ дд-мм-гггг) или оставьте пустым: ");
+                print!("Введите срок выполнения (ЧЧ:ММ дд-мм-гггг, \"tomorrow 5pm\", \"in 3 days\" и т.п.) или оставьте пустым: ");
                 io::stdout().flush().unwrap();
                 let due_time = read_line();
                 let due_time = if due_time.is_empty() {
                     None
                 } else {
-                    match NaiveDateTime::parse_from_str(&due_time, "%H:%M %d-%m-%Y") {
+                    match parse_due_time(&due_time) {
                         Ok(datetime) => Some(datetime),
-                        Err(_) => {
-                            println!("Неверный формат даты. Срок выполнения оставлен пустым.");
+                        Err(err) => {
+                            println!("{} Срок выполнения оставлен пустым.", err);
                             None
                         }
                     }
                 };
 
-                task_manager.add_task(description, priority, due_time);
+                print!("Введите теги через запятую или оставьте пустым: ");
+                io::stdout().flush().unwrap();
+                let tags: HashSet<String> = read_line()
+                    .split(',')
+                    .map(|tag| tag.trim().to_string())
+                    .filter(|tag| !tag.is_empty())
+                    .collect();
+
+                task_manager.add_task(description, priority, due_time, tags);
                 println!("Задача добавлена.");
             }
-            "complete" => {  // Если пользователь ввел "complete", отмечаем задачу как завершенную.
+            "filter" => {  // Если пользователь ввел "filter", выводим только задачи с указанным тегом.
+                print!("Введите тег для фильтрации: ");
+                io::stdout().flush().unwrap();
+                let tag = read_line();
+                task_manager.print_tasks_with_tag(&tag);
+            }
+            "track" => {  // Если пользователь ввел "track", добавляем запись о потраченном на задачу времени.
                 task_manager.print_tasks();
-                print!("Введите индекс задачи для завершения: ");
+                print!("Введите ID задачи: ");
                 io::stdout().flush().unwrap();
-                if let Ok(index) = read_line().parse::<usize>() {
-                    task_manager.complete_task(index);
-                    println!("Задача завершена.");
+                let id: u64 = match read_line().parse() {
+                    Ok(id) => id,
+                    Err(_) => {
+                        println!("Неверный ID.");
+                        continue;
+                    }
+                };
+
+                print!("Введите часы: ");
+                io::stdout().flush().unwrap();
+                let hours: u16 = read_line().parse().unwrap_or(0);
+
+                print!("Введите минуты: ");
+                io::stdout().flush().unwrap();
+                let minutes: u16 = read_line().parse().unwrap_or(0);
+
+                if task_manager.track_time(id, Duration::new(hours, minutes)) {
+                    println!("Время добавлено.");
                 } else {
-                    println!("Неверный индекс.");
+                    println!("Задача с таким ID не найдена.");
+                }
+            }
+            "depend" => {  // Если пользователь ввел "depend", связываем одну задачу зависимостью от другой по ID.
+                task_manager.print_tasks();
+                print!("Введите ID задачи, которая зависит: ");
+                io::stdout().flush().unwrap();
+                let task_id: u64 = match read_line().parse() {
+                    Ok(id) => id,
+                    Err(_) => {
+                        println!("Неверный ID.");
+                        continue;
+                    }
+                };
+
+                print!("Введите ID задачи, от которой она зависит: ");
+                io::stdout().flush().unwrap();
+                let depends_on_id: u64 = match read_line().parse() {
+                    Ok(id) => id,
+                    Err(_) => {
+                        println!("Неверный ID.");
+                        continue;
+                    }
+                };
+
+                match task_manager.add_dependency(task_id, depends_on_id) {
+                    Ok(()) => println!("Зависимость добавлена."),
+                    Err(err) => println!("Ошибка: {}", err),
+                }
+            }
+            "complete" => {  // Если пользователь ввел "complete", отмечаем задачу как завершенную по её ID.
+                task_manager.print_tasks();
+                print!("Введите ID задачи для завершения: ");
+                io::stdout().flush().unwrap();
+                match read_line().parse::<u64>() {
+                    Ok(id) if task_manager.complete_task_by_id(id) => println!("Задача завершена."),
+                    Ok(_) => println!("Задача с таким ID не найдена."),
+                    Err(_) => println!("Неверный ID."),
+                }
+            }
+            "delete" => {  // Если пользователь ввел "delete", удаляем задачу по её ID.
+                task_manager.print_tasks();
+                print!("Введите ID задачи для удаления: ");
+                io::stdout().flush().unwrap();
+                match read_line().parse::<u64>() {
+                    Ok(id) if task_manager.delete_task_by_id(id) => println!("Задача удалена."),
+                    Ok(_) => println!("Задача с таким ID не найдена."),
+                    Err(_) => println!("Неверный ID."),
                 }
             }
             "print" => {
                 task_manager.print_tasks();
             }
+            "list" => {  // Если пользователь ввел "list", выводим задачи в табличном виде.
+                task_manager.print_tasks_table();
+            }
             "quit" => {
                 if let Err(err) = task_manager.save_tasks() {
                     eprintln!("Ошибка при сохранении задач: {}", err);
@@ -206,4 +523,116 @@ fn read_line() -> String {   // Эта функция read_line() читает 
     input.trim().to_string()
 }
 
+// Разбирает срок выполнения: сперва строгий формат "ЧЧ:ММ дд-мм-гггг", затем гибкие фразы
+// ("today", "tomorrow", "in N days/hours", дни недели, голое время вроде "5pm") относительно Local::now().
+fn parse_due_time(input: &str) -> Result<NaiveDateTime, String> {
+    if let Ok(datetime) = NaiveDateTime::parse_from_str(input, "%H:%M %d-%m-%Y") {
+        return Ok(datetime);
+    }
+
+    let now = Local::now().naive_local();
+    let normalized = input.trim().to_lowercase();
+
+    if let Some(rest) = normalized.strip_prefix("in ") {
+        let parts: Vec<&str> = rest.split_whitespace().collect();
+        if let [amount, unit] = parts[..] {
+            if let Ok(amount) = amount.parse::<i64>() {
+                match unit.trim_end_matches('s') {
+                    "day" => return Ok(now + ChronoDuration::days(amount)),
+                    "hour" => return Ok(now + ChronoDuration::hours(amount)),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    let weekdays = [
+        ("monday", Weekday::Mon), ("tuesday", Weekday::Tue), ("wednesday", Weekday::Wed),
+        ("thursday", Weekday::Thu), ("friday", Weekday::Fri), ("saturday", Weekday::Sat),
+        ("sunday", Weekday::Sun),
+    ];
+
+    // "today"/"tomorrow"/weekday name, optionally followed by a bare time (e.g. "tomorrow 5pm").
+    let mut words = normalized.splitn(2, char::is_whitespace);
+    let day_word = words.next().unwrap_or("");
+    let rest = words.next().map(str::trim).unwrap_or("");
+
+    let date = if day_word == "today" {
+        Some(now.date())
+    } else if day_word == "tomorrow" {
+        Some(now.date() + ChronoDuration::days(1))
+    } else if let Some(&(_, weekday)) = weekdays.iter().find(|(name, _)| *name == day_word) {
+        let mut date = now.date() + ChronoDuration::days(1);
+        while date.weekday() != weekday {
+            date += ChronoDuration::days(1);
+        }
+        Some(date)
+    } else {
+        None
+    };
+
+    if let Some(date) = date {
+        return Ok(match parse_bare_time(rest) {
+            Some(time) => date.and_time(time),
+            None => date.and_hms_opt(23, 59, 0).unwrap(),
+        });
+    }
+
+    if let Some(time) = parse_bare_time(&normalized) {
+        let today_at_time = now.date().and_time(time);
+        return Ok(if today_at_time > now { today_at_time } else { (now.date() + ChronoDuration::days(1)).and_time(time) });
+    }
+
+    Err(format!("Не удалось распознать срок выполнения: \"{}\"", input))
+}
+
+// Разбирает голое время вроде "5pm" или "5:30am" в NaiveTime. chrono не умеет строить
+// NaiveTime из часа+am/pm без минут через "%I%p", поэтому часы и минуты разбираются вручную.
+fn parse_bare_time(input: &str) -> Option<NaiveTime> {
+    let input = input.trim();
+    let (digits, is_pm) = if let Some(stripped) = input.strip_suffix("pm") {
+        (stripped, true)
+    } else if let Some(stripped) = input.strip_suffix("am") {
+        (stripped, false)
+    } else {
+        return None;
+    };
+
+    let (hour_str, minute_str) = match digits.trim().split_once(':') {
+        Some((hour_str, minute_str)) => (hour_str, minute_str),
+        None => (digits.trim(), "0"),
+    };
+    let hour: u32 = hour_str.parse().ok()?;
+    let minute: u32 = minute_str.parse().ok()?;
+    if !(1..=12).contains(&hour) || minute >= 60 {
+        return None;
+    }
+
+    let hour24 = match (hour, is_pm) {
+        (12, true) => 12,
+        (12, false) => 0,
+        (hour, true) => hour + 12,
+        (hour, false) => hour,
+    };
+    NaiveTime::from_hms_opt(hour24, minute, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_bare_5pm() {
+        assert_eq!(parse_bare_time("5pm"), NaiveTime::from_hms_opt(17, 0, 0));
+    }
+
+    #[test]
+    fn parses_tomorrow_5pm() {
+        let parsed = parse_due_time("tomorrow 5pm").unwrap();
+        let expected_date = Local::now().naive_local().date() + ChronoDuration::days(1);
+        assert_eq!(parsed.date(), expected_date);
+        assert_eq!(parsed.time(), NaiveTime::from_hms_opt(17, 0, 0).unwrap());
+    }
+}
+
 